@@ -33,9 +33,24 @@ pub type Result<T> = std::result::Result<T, Error>;
 pub enum ErrorKind {
     /// Redis don't know what happened here, and no actions other than just
     /// returning it back.
-    Unexpected,  
+    Unexpected,
     /// The config for backend is invalid.
     ConfigInvalid,
+    /// The connection to the server could not be established or was dropped.
+    ConnectionFailed,
+    /// The server rejected our credentials (e.g. `NOAUTH`/`WRONGPASS`).
+    AuthenticationFailed,
+    /// The operation did not complete within the allotted time.
+    Timeout,
+    /// The cluster is down and cannot currently serve the request.
+    ClusterDown,
+    /// The requested key's slot has permanently moved to another node (`MOVED`).
+    Moved,
+    /// The requested key's slot is being migrated to another node (`ASK`).
+    Ask,
+    /// The server replied with an error, or its reply could not be parsed as
+    /// the expected type.
+    ResponseError,
 }
 
 impl ErrorKind {
@@ -57,6 +72,13 @@ impl From<ErrorKind> for &'static str {
       match v {
           ErrorKind::Unexpected => "Unexpected",
           ErrorKind::ConfigInvalid => "ConfigInvalid",
+          ErrorKind::ConnectionFailed => "ConnectionFailed",
+          ErrorKind::AuthenticationFailed => "AuthenticationFailed",
+          ErrorKind::Timeout => "Timeout",
+          ErrorKind::ClusterDown => "ClusterDown",
+          ErrorKind::Moved => "Moved",
+          ErrorKind::Ask => "Ask",
+          ErrorKind::ResponseError => "ResponseError",
       }
   }
 }
@@ -224,4 +246,34 @@ impl Error {
         self.source = Some(src.into());
         self
     }
+
+    /// Return this error's kind.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Whether this error is transient and the operation that caused it may
+    /// succeed if retried, e.g. a dropped connection or the cluster being down.
+    ///
+    /// `Moved`/`Ask` are deliberately excluded: on the cluster path redis-rs's
+    /// `cluster_async` driver already follows these redirections internally,
+    /// so they never surface here; on the single-node path there is no other
+    /// node to redirect to, so retrying the same connection could never
+    /// resolve one.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self.kind,
+            ErrorKind::ConnectionFailed | ErrorKind::Timeout | ErrorKind::ClusterDown
+        )
+    }
+
+    /// Like [`Error::is_retryable`], but also excludes `Timeout`.
+    ///
+    /// A timed-out write may have already been applied by the server before
+    /// the timeout fired; retrying it risks re-applying it (e.g. duplicating
+    /// bytes appended by [`crate::RedisClient::append`]). Use this instead of
+    /// [`Error::is_retryable`] for non-idempotent mutations.
+    pub fn is_retryable_write(&self) -> bool {
+        self.is_retryable() && self.kind != ErrorKind::Timeout
+    }
 }
@@ -2,6 +2,11 @@ pub mod redis_client;
 pub mod error;
 pub mod settings;
 
+pub use redis_client::Message;
+pub use redis_client::Pipeline;
+pub use redis_client::PipelineValue;
+pub use redis_client::ReadPreference;
 pub use redis_client::RedisClient;
+pub use redis_client::Subscription;
 pub use error::Error;
 pub use settings::RedisSettings;
\ No newline at end of file
@@ -1,7 +1,12 @@
 // This code is an adaptation of the code from
 // https://github.com/apache/incubator-opendal/blob/main/core/src/services/redis/backend.rs
 
+use async_trait::async_trait;
+use bb8::Pool;
+use bb8::RunError;
+use futures::StreamExt;
 use http::Uri;
+use rand::Rng;
 use redis::aio::ConnectionManager;
 use redis::cluster::ClusterClient;
 use redis::cluster::ClusterClientBuilder;
@@ -13,7 +18,6 @@ use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::path::PathBuf;
 use std::time::Duration;
-use tokio::sync::OnceCell;
 
 use crate::error::Error;
 use crate::error::ErrorKind;
@@ -22,9 +26,84 @@ use crate::settings::RedisSettings;
 
 const DEFAULT_REDIS_ENDPOINT: &str = "tcp://127.0.0.1:6379";
 const DEFAULT_REDIS_PORT: u16 = 6379;
+const DEFAULT_POOL_MAX_SIZE: u32 = 10;
+const DEFAULT_RETRY_BASE_DELAY: Duration = Duration::from_millis(50);
+
+fn classify_redis_error(e: &RedisError) -> ErrorKind {
+    if e.is_timeout() {
+        return ErrorKind::Timeout;
+    }
+    if e.is_connection_refusal() || e.is_connection_dropped() || e.is_io_error() {
+        return ErrorKind::ConnectionFailed;
+    }
+    if e.is_cluster_error() {
+        return match e.kind() {
+            redis::ErrorKind::Moved => ErrorKind::Moved,
+            redis::ErrorKind::Ask => ErrorKind::Ask,
+            _ => ErrorKind::ClusterDown,
+        };
+    }
+
+    match e.kind() {
+        redis::ErrorKind::AuthenticationFailed => ErrorKind::AuthenticationFailed,
+        redis::ErrorKind::ResponseError | redis::ErrorKind::TypeError => ErrorKind::ResponseError,
+        _ => ErrorKind::Unexpected,
+    }
+}
 
 fn format_redis_error(e: RedisError) -> Error {
-    Error::new(ErrorKind::Unexpected, e.category()).set_source(e)
+    Error::new(classify_redis_error(&e), e.category()).set_source(e)
+}
+
+fn format_pool_error(e: RunError<RedisError>) -> Error {
+    match e {
+        RunError::User(e) => format_redis_error(e),
+        RunError::TimedOut => Error::new(
+            ErrorKind::Timeout,
+            "timed out waiting for a pooled connection",
+        ),
+    }
+}
+
+fn read_pem(path: &std::path::Path, what: &'static str) -> Result<Vec<u8>> {
+    std::fs::read(path).map_err(|e| {
+        Error::new(ErrorKind::ConfigInvalid, "failed to read TLS file")
+            .with_context("kind", what)
+            .with_context("path", path.display().to_string())
+            .set_source(e)
+    })
+}
+
+fn build_tls_certificates(settings: &RedisSettings) -> Result<Option<redis::TlsCertificates>> {
+    if settings.tls_client_cert.is_some() != settings.tls_client_key.is_some() {
+        return Err(Error::new(
+            ErrorKind::ConfigInvalid,
+            "tls_client_cert and tls_client_key must be set together",
+        ));
+    }
+
+    let root_cert = settings
+        .tls_ca_cert
+        .as_deref()
+        .map(|path| read_pem(path, "tls_ca_cert"))
+        .transpose()?;
+
+    let client_tls = match (&settings.tls_client_cert, &settings.tls_client_key) {
+        (Some(cert_path), Some(key_path)) => Some(redis::ClientTlsConfig {
+            client_cert: read_pem(cert_path, "tls_client_cert")?,
+            client_key: read_pem(key_path, "tls_client_key")?,
+        }),
+        _ => None,
+    };
+
+    if root_cert.is_none() && client_tls.is_none() {
+        return Ok(None);
+    }
+
+    Ok(Some(redis::TlsCertificates {
+        client_tls,
+        root_cert,
+    }))
 }
 
 fn get_connection_info(endpoint: String, settings: &RedisSettings) -> Result<ConnectionInfo> {
@@ -52,8 +131,8 @@ fn get_connection_info(endpoint: String, settings: &RedisSettings) -> Result<Con
             ConnectionAddr::TcpTls {
                 host,
                 port,
-                insecure: false,
-                tls_params: None,
+                insecure: settings.tls_insecure,
+                tls_params: build_tls_certificates(settings)?,
             }
         }
         Some("unix") | Some("redis+unix") => {
@@ -80,18 +159,141 @@ fn get_connection_info(endpoint: String, settings: &RedisSettings) -> Result<Con
     })
 }
 
-#[derive(Clone)]
-enum RedisConnection {
+enum RedisConnectionKind {
     Single(ConnectionManager),
     Cluster(ClusterConnection),
 }
 
+/// A pooled connection, plus whether a command has marked it broken so that
+/// bb8 discards it instead of returning it to the pool on check-in.
+struct RedisConnection {
+    kind: RedisConnectionKind,
+    broken: bool,
+}
+
+/// A `bb8::ManageConnection` that opens either a single-node or a cluster
+/// connection, depending on which kind of `RedisClient` created it.
+#[derive(Clone)]
+enum RedisConnectionManager {
+    Single(Client),
+    Cluster(ClusterClient),
+}
+
+#[async_trait]
+impl bb8::ManageConnection for RedisConnectionManager {
+    type Connection = RedisConnection;
+    type Error = RedisError;
+
+    async fn connect(&self) -> std::result::Result<Self::Connection, Self::Error> {
+        let kind = match self {
+            RedisConnectionManager::Single(client) => ConnectionManager::new(client.clone())
+                .await
+                .map(RedisConnectionKind::Single)?,
+            RedisConnectionManager::Cluster(client) => client
+                .clone()
+                .get_async_connection()
+                .await
+                .map(RedisConnectionKind::Cluster)?,
+        };
+        Ok(RedisConnection {
+            kind,
+            broken: false,
+        })
+    }
+
+    async fn is_valid(&self, conn: &mut Self::Connection) -> std::result::Result<(), Self::Error> {
+        match &mut conn.kind {
+            RedisConnectionKind::Single(conn) => redis::cmd("PING").query_async(conn).await,
+            RedisConnectionKind::Cluster(conn) => redis::cmd("PING").query_async(conn).await,
+        }
+    }
+
+    fn has_broken(&self, conn: &mut Self::Connection) -> bool {
+        conn.broken
+    }
+}
+
+/// The retry/backoff policy applied to transient command failures.
+#[derive(Clone, Copy)]
+struct RetryPolicy {
+    max_retries: u32,
+    base_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Exponential backoff with jitter for the given (1-indexed) retry attempt.
+    fn delay_for(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(10);
+        let backoff = self.base_delay.saturating_mul(1u32 << exponent);
+        let jitter = rand::thread_rng().gen_range(0.5..1.5);
+        backoff.mul_f64(jitter)
+    }
+}
+
+/// Run a single command against a connection checked out of `$pool`, retrying
+/// on transient failures per `$self`'s `RetryPolicy` until it succeeds, a
+/// non-retryable error is hit, or the retry budget is exhausted.
+///
+/// Defaults to [`Error::is_retryable`]; pass a third argument to use a
+/// stricter predicate, e.g. [`Error::is_retryable_write`] for non-idempotent
+/// mutations.
+macro_rules! with_retry {
+    ($self:expr, $pool:expr, |$conn:ident| $body:expr) => {
+        with_retry!($self, $pool, |$conn| $body, Error::is_retryable)
+    };
+    ($self:expr, $pool:expr, |$conn:ident| $body:expr, $retryable:expr) => {{
+        let mut attempt = 0u32;
+        loop {
+            let mut $conn = $pool.get().await.map_err(format_pool_error)?;
+            let result: Result<_> = async { $body }.await;
+            match result {
+                Ok(value) => break Ok(value),
+                Err(err) if $retryable(&err) && attempt < $self.retry.max_retries => {
+                    $conn.broken = true;
+                    drop($conn);
+                    attempt += 1;
+                    tokio::time::sleep($self.retry.delay_for(attempt)).await;
+                }
+                Err(err) => break Err(err),
+            }
+        }
+    }};
+}
+
+/// A per-call read routing hint for cluster deployments with
+/// `read_from_replicas` enabled. Without a replica pool (always the case for
+/// single-node `RedisClient`s), `PreferReplica` reads from the primary pool
+/// and `ReplicaOnly` returns a `ConfigInvalid` error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReadPreference {
+    /// Always read from a primary. The default.
+    #[default]
+    Primary,
+    /// Read from a replica if one is available, falling back to a primary.
+    PreferReplica,
+    /// Like `PreferReplica`, but error out instead of silently falling back
+    /// to the primary pool when no replica pool is configured.
+    ///
+    /// This does not guarantee every read is served by a replica: redis-rs's
+    /// `read_from_replicas()` only prefers replicas for the underlying
+    /// cluster routing, so a slot with no reachable replica may still be
+    /// served by its primary.
+    ReplicaOnly,
+}
+
 #[derive(Clone)]
 pub struct RedisClient {
     addresses: String,
-    client: Option<Client>,
-    cluster_client: Option<ClusterClient>,
-    conn: OnceCell<RedisConnection>,
+    pool: Pool<RedisConnectionManager>,
+    // A plain, non-cluster-aware client used only to open dedicated pub/sub
+    // connections. `redis::cluster::ClusterClient` has no pub/sub support, so
+    // in cluster mode this points at a single node instead of the cluster;
+    // see `open_pubsub` for the caveat that follows from that.
+    pubsub_client: Client,
+    retry: RetryPolicy,
+    // Only set in cluster mode with `read_from_replicas` enabled; a second
+    // pool built from a `ClusterClient` configured to route reads to replicas.
+    replica_pool: Option<Pool<RedisConnectionManager>>,
 }
 
 // implement `Debug` manually, or password may be leaked.
@@ -106,27 +308,48 @@ impl Debug for RedisClient {
 
 impl RedisClient {
     pub fn new(settings: &RedisSettings) -> Result<Self> {
-        if let Some(addresses) = settings.addresses.clone() {
+        let mut replica_manager: Option<RedisConnectionManager> = None;
+
+        let (addresses, manager, pubsub_client) = if let Some(addresses) =
+            settings.addresses.clone()
+        {
             let mut cluser_addresses: Vec<ConnectionInfo> = Vec::default();
             for address in addresses.split(",") {
                 cluser_addresses.push(get_connection_info(address.to_string(), settings)?);
             }
 
-            let mut client_builder = ClusterClientBuilder::new(cluser_addresses);
-            if let Some(username) = &settings.username {
-                client_builder = client_builder.username(username.clone());
-            }
-            if let Some(password) = &settings.password {
-                client_builder = client_builder.password(password.clone());
+            let build_cluster_client = |read_from_replicas: bool| -> Result<ClusterClient> {
+                let mut client_builder = ClusterClientBuilder::new(cluser_addresses.clone());
+                if let Some(username) = &settings.username {
+                    client_builder = client_builder.username(username.clone());
+                }
+                if let Some(password) = &settings.password {
+                    client_builder = client_builder.password(password.clone());
+                }
+                if read_from_replicas {
+                    client_builder = client_builder.read_from_replicas();
+                }
+                client_builder.build().map_err(format_redis_error)
+            };
+
+            let client = build_cluster_client(false)?;
+            if settings.read_from_replicas {
+                replica_manager = Some(RedisConnectionManager::Cluster(build_cluster_client(
+                    true,
+                )?));
             }
-            let client = client_builder.build().map_err(format_redis_error)?;
 
-            Ok(Self {
+            // `ClusterClient` has no pub/sub support, so pub/sub connects
+            // directly to a single node instead of going through the
+            // cluster client; see `open_pubsub`.
+            let pubsub_client =
+                Client::open(cluser_addresses[0].clone()).map_err(format_redis_error)?;
+
+            (
                 addresses,
-                client: None,
-                cluster_client: Some(client),
-                conn: OnceCell::new(),
-            })
+                RedisConnectionManager::Cluster(client),
+                pubsub_client,
+            )
         } else {
             let address = settings
                 .address
@@ -141,99 +364,446 @@ impl RedisClient {
                         .set_source(e)
                 })?;
 
-            Ok(Self {
-                addresses: address,
-                client: Some(client),
-                cluster_client: None,
-                conn: OnceCell::new(),
-            })
+            let pubsub_client = client.clone();
+            (address, RedisConnectionManager::Single(client), pubsub_client)
+        };
+
+        let make_pool_builder = || {
+            let mut pool_builder = Pool::builder()
+                .max_size(settings.pool_max_size.unwrap_or(DEFAULT_POOL_MAX_SIZE))
+                // `ConnectionManager`/`ClusterConnection` already reconnect
+                // transparently underneath us, and `has_broken` evicts a
+                // connection a command has marked broken, so there's no need
+                // to pay for a `PING` round-trip on every check-out on top of
+                // the command we're about to run.
+                .test_on_check_out(false);
+            if let Some(min_idle) = settings.min_idle {
+                pool_builder = pool_builder.min_idle(Some(min_idle));
+            }
+            if let Some(connection_timeout) = settings.connection_timeout {
+                pool_builder = pool_builder.connection_timeout(connection_timeout);
+            }
+            pool_builder
+        };
+
+        // `build_unchecked` does not wait for any connections to be
+        // established. Unless `min_idle` is set (in which case that many are
+        // opened in the background), no connection is opened before the first
+        // check-out, matching the lazily-connected behavior the client had
+        // before pooling.
+        let pool = make_pool_builder().build_unchecked(manager.clone());
+        let replica_pool =
+            replica_manager.map(|replica_manager| make_pool_builder().build_unchecked(replica_manager));
+
+        let retry = RetryPolicy {
+            max_retries: settings.max_retries.unwrap_or(0),
+            base_delay: settings.retry_base_delay.unwrap_or(DEFAULT_RETRY_BASE_DELAY),
+        };
+
+        Ok(Self {
+            addresses,
+            pool,
+            pubsub_client,
+            retry,
+            replica_pool,
+        })
+    }
+
+    fn pool_for(&self, preference: ReadPreference) -> Result<&Pool<RedisConnectionManager>> {
+        match preference {
+            ReadPreference::Primary => Ok(&self.pool),
+            ReadPreference::PreferReplica => Ok(self.replica_pool.as_ref().unwrap_or(&self.pool)),
+            ReadPreference::ReplicaOnly => self.replica_pool.as_ref().ok_or_else(|| {
+                Error::new(
+                    ErrorKind::ConfigInvalid,
+                    "no replica pool configured; set `read_from_replicas` to use ReadPreference::ReplicaOnly",
+                )
+            }),
         }
     }
 
-    async fn connect(&self) -> Result<RedisConnection> {
-        Ok(self
-            .conn
-            .get_or_try_init(|| async {
-                if let Some(client) = self.client.clone() {
-                    ConnectionManager::new(client.clone())
+    pub async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        self.get_with_preference(key, ReadPreference::Primary).await
+    }
+
+    /// Like [`RedisClient::get`], but with an explicit [`ReadPreference`] for
+    /// cluster deployments with `read_from_replicas` enabled.
+    pub async fn get_with_preference(
+        &self,
+        key: &str,
+        preference: ReadPreference,
+    ) -> Result<Option<Vec<u8>>> {
+        let pool = self.pool_for(preference)?;
+        with_retry!(self, pool, |conn| {
+            match &mut conn.kind {
+                RedisConnectionKind::Single(conn) => conn.get(key).await.map_err(format_redis_error),
+                RedisConnectionKind::Cluster(conn) => conn.get(key).await.map_err(format_redis_error),
+            }
+        })
+    }
+
+    /// A timed-out `set`/`set_ex` is not retried: the server may have already
+    /// applied it before the timeout fired, and retrying risks clobbering a
+    /// newer value written by someone else in the meantime.
+    pub async fn set(&self, key: &str, value: &[u8], ttl: Option<Duration>) -> Result<()> {
+        with_retry!(
+            self,
+            self.pool,
+            |conn| {
+                match ttl {
+                    Some(ttl) => match &mut conn.kind {
+                        RedisConnectionKind::Single(conn) => conn
+                            .set_ex(key, value, ttl.as_secs())
+                            .await
+                            .map_err(format_redis_error),
+                        RedisConnectionKind::Cluster(conn) => conn
+                            .set_ex(key, value, ttl.as_secs())
+                            .await
+                            .map_err(format_redis_error),
+                    },
+                    None => match &mut conn.kind {
+                        RedisConnectionKind::Single(conn) => {
+                            conn.set(key, value).await.map_err(format_redis_error)
+                        }
+                        RedisConnectionKind::Cluster(conn) => {
+                            conn.set(key, value).await.map_err(format_redis_error)
+                        }
+                    },
+                }
+            },
+            Error::is_retryable_write
+        )
+    }
+
+    pub async fn delete(&self, key: &str) -> Result<()> {
+        with_retry!(self, self.pool, |conn| {
+            match &mut conn.kind {
+                RedisConnectionKind::Single(conn) => {
+                    conn.del::<_, ()>(key).await.map_err(format_redis_error)
+                }
+                RedisConnectionKind::Cluster(conn) => {
+                    conn.del::<_, ()>(key).await.map_err(format_redis_error)
+                }
+            }
+        })
+    }
+
+    /// A timed-out `append` is not retried: unlike `set`, `append` is not
+    /// idempotent, and the server may have already applied it before the
+    /// timeout fired, which would duplicate `value` on retry.
+    pub async fn append(&self, key: &str, value: &[u8]) -> Result<()> {
+        with_retry!(
+            self,
+            self.pool,
+            |conn| {
+                match &mut conn.kind {
+                    RedisConnectionKind::Single(conn) => conn
+                        .append::<_, _, ()>(key, value)
                         .await
-                        .map(RedisConnection::Single)
-                } else {
-                    self.cluster_client
-                        .clone()
-                        .unwrap()
-                        .get_async_connection()
+                        .map_err(format_redis_error),
+                    RedisConnectionKind::Cluster(conn) => conn
+                        .append::<_, _, ()>(key, value)
                         .await
-                        .map(RedisConnection::Cluster)
+                        .map_err(format_redis_error),
                 }
-            })
-            .await
-            .map_err(format_redis_error)?
-            .clone())
+            },
+            Error::is_retryable_write
+        )
     }
 
-    pub async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
-        let conn = self.connect().await?;
-        match conn {
-            RedisConnection::Single(mut conn) => {
-                let bs = conn.get(key).await.map_err(format_redis_error)?;
-                Ok(bs)
+    /// Fetch multiple keys in a single round-trip.
+    ///
+    /// The result is positional: `result[i]` is the value for `keys[i]`.
+    ///
+    /// In cluster mode all keys must hash to the same slot (e.g. via a
+    /// `{hashtag}` shared across them); otherwise the server rejects the
+    /// pipeline with a `CROSSSLOT` error instead of routing it per node.
+    pub async fn mget(&self, keys: &[&str]) -> Result<Vec<Option<Vec<u8>>>> {
+        self.mget_with_preference(keys, ReadPreference::Primary).await
+    }
+
+    /// Like [`RedisClient::mget`], but with an explicit [`ReadPreference`] for
+    /// cluster deployments with `read_from_replicas` enabled.
+    pub async fn mget_with_preference(
+        &self,
+        keys: &[&str],
+        preference: ReadPreference,
+    ) -> Result<Vec<Option<Vec<u8>>>> {
+        let pool = self.pool_for(preference)?;
+        with_retry!(self, pool, |conn| {
+            let mut pipe = redis::pipe();
+            for key in keys {
+                pipe.get(*key);
             }
-            RedisConnection::Cluster(mut conn) => {
-                let bs = conn.get(key).await.map_err(format_redis_error)?;
-                Ok(bs)
+            match &mut conn.kind {
+                RedisConnectionKind::Single(conn) => {
+                    pipe.query_async(conn).await.map_err(format_redis_error)
+                }
+                RedisConnectionKind::Cluster(conn) => {
+                    pipe.query_async(conn).await.map_err(format_redis_error)
+                }
             }
-        }
+        })
     }
 
-    pub async fn set(&self, key: &str, value: &[u8], ttl: Option<Duration>) -> Result<()> {
-        let conn = self.connect().await?;
-        match ttl {
-            Some(ttl) => match conn {
-                RedisConnection::Single(mut conn) => conn
-                    .set_ex(key, value, ttl.as_secs())
-                    .await
-                    .map_err(format_redis_error)?,
-                RedisConnection::Cluster(mut conn) => conn
-                    .set_ex(key, value, ttl.as_secs())
-                    .await
-                    .map_err(format_redis_error)?,
+    /// Set multiple key/value pairs in a single, atomic round-trip.
+    ///
+    /// In cluster mode all keys must hash to the same slot (e.g. via a
+    /// `{hashtag}` shared across them); otherwise the server rejects the
+    /// `MULTI`/`EXEC` with a `CROSSSLOT` error instead of routing it per node.
+    ///
+    /// A timed-out `mset` is not retried, for the same reason as [`RedisClient::set`].
+    pub async fn mset(&self, pairs: &[(&str, &[u8])]) -> Result<()> {
+        with_retry!(
+            self,
+            self.pool,
+            |conn| {
+                let mut pipe = redis::pipe();
+                pipe.atomic();
+                for (key, value) in pairs {
+                    pipe.set(*key, *value).ignore();
+                }
+                match &mut conn.kind {
+                    RedisConnectionKind::Single(conn) => {
+                        pipe.query_async(conn).await.map_err(format_redis_error)
+                    }
+                    RedisConnectionKind::Cluster(conn) => {
+                        pipe.query_async(conn).await.map_err(format_redis_error)
+                    }
+                }
             },
-            None => match conn {
-                RedisConnection::Single(mut conn) => {
-                    conn.set(key, value).await.map_err(format_redis_error)?
+            Error::is_retryable_write
+        )
+    }
+
+    /// Start building a [`Pipeline`] of commands to run together in a single round-trip.
+    pub fn pipeline(&self) -> Pipeline<'_> {
+        Pipeline::new(self)
+    }
+
+    /// Run an arbitrary Redis command through the same pooled connection,
+    /// cluster dispatch, and retry logic as the typed methods, for verbs
+    /// (`INCR`, `EXPIRE`, `HSET`, `SCAN`, `TTL`, ...) this crate doesn't wrap.
+    ///
+    /// Decode the reply with [`redis::FromRedisValue`].
+    ///
+    /// Since the crate can't know whether an arbitrary command is idempotent,
+    /// a timed-out command is not retried: `cmd` may be something like
+    /// `INCR`/`LPUSH`/`APPEND` that the server already applied before the
+    /// timeout fired, and replaying it would double-apply it. Other
+    /// transient failures (a dropped connection, the cluster being down)
+    /// are still retried.
+    pub async fn command(&self, cmd: &redis::Cmd) -> Result<redis::Value> {
+        with_retry!(
+            self,
+            self.pool,
+            |conn| {
+                match &mut conn.kind {
+                    RedisConnectionKind::Single(conn) => {
+                        cmd.query_async(conn).await.map_err(format_redis_error)
+                    }
+                    RedisConnectionKind::Cluster(conn) => {
+                        cmd.query_async(conn).await.map_err(format_redis_error)
+                    }
                 }
-                RedisConnection::Cluster(mut conn) => {
-                    conn.set(key, value).await.map_err(format_redis_error)?
+            },
+            Error::is_retryable_write
+        )
+    }
+
+    /// Convenience wrapper over [`RedisClient::command`] for building a
+    /// command from a verb and its raw argument bytes.
+    pub async fn cmd(&self, name: &str, args: &[&[u8]]) -> Result<redis::Value> {
+        let mut cmd = redis::cmd(name);
+        for arg in args {
+            cmd.arg(*arg);
+        }
+        self.command(&cmd).await
+    }
+
+    /// Publish `payload` on `channel`, returning the number of subscribers that received it.
+    ///
+    /// A timed-out publish is not retried: the message may have already
+    /// reached the server and been delivered to subscribers before the
+    /// timeout fired, and retrying would deliver it a second time.
+    pub async fn publish(&self, channel: &str, payload: &[u8]) -> Result<u64> {
+        with_retry!(
+            self,
+            self.pool,
+            |conn| {
+                match &mut conn.kind {
+                    RedisConnectionKind::Single(conn) => {
+                        conn.publish(channel, payload).await.map_err(format_redis_error)
+                    }
+                    RedisConnectionKind::Cluster(conn) => {
+                        conn.publish(channel, payload).await.map_err(format_redis_error)
+                    }
                 }
             },
+            Error::is_retryable_write
+        )
+    }
+
+    /// Subscribe to one or more exact channel names.
+    ///
+    /// Pub/sub needs a dedicated connection rather than one checked out of the
+    /// pool, so this opens a fresh connection from the underlying client. In
+    /// cluster mode this connects directly to a single node rather than
+    /// through the cluster client, since `ClusterClient` has no pub/sub
+    /// support; only non-sharded `PUBLISH`/`SUBSCRIBE` (which the cluster
+    /// broadcasts to every node) work this way, not sharded `SSUBSCRIBE`.
+    pub async fn subscribe(&self, channels: &[&str]) -> Result<Subscription> {
+        let mut pubsub = self.open_pubsub().await?;
+        for channel in channels {
+            pubsub.subscribe(*channel).await.map_err(format_redis_error)?;
         }
-        Ok(())
+        Ok(Subscription { pubsub })
     }
 
-    pub async fn delete(&self, key: &str) -> Result<()> {
-        let conn = self.connect().await?;
-        match conn {
-            RedisConnection::Single(mut conn) => {
-                let _: () = conn.del(key).await.map_err(format_redis_error)?;
-            }
-            RedisConnection::Cluster(mut conn) => {
-                let _: () = conn.del(key).await.map_err(format_redis_error)?;
-            }
+    /// Subscribe to one or more glob-style channel patterns (`news.*`).
+    pub async fn psubscribe(&self, patterns: &[&str]) -> Result<Subscription> {
+        let mut pubsub = self.open_pubsub().await?;
+        for pattern in patterns {
+            pubsub.psubscribe(*pattern).await.map_err(format_redis_error)?;
         }
-        Ok(())
+        Ok(Subscription { pubsub })
     }
 
-    pub async fn append(&self, key: &str, value: &[u8]) -> Result<()> {
-        let conn = self.connect().await?;
-        match conn {
-            RedisConnection::Single(mut conn) => {
-                conn.append(key, value).await.map_err(format_redis_error)?;
+    /// `redis::cluster::ClusterClient` has no pub/sub support, so
+    /// `pubsub_client` is a plain `Client` pointed at a single node (the
+    /// first configured cluster node, in cluster mode). Classic pub/sub
+    /// messages are broadcast to every node in a Redis Cluster, so any one
+    /// node is sufficient to receive non-sharded `PUBLISH`/`SUBSCRIBE`
+    /// traffic; sharded `SSUBSCRIBE` channels are not supported.
+    async fn open_pubsub(&self) -> Result<redis::aio::PubSub> {
+        self.pubsub_client
+            .get_async_pubsub()
+            .await
+            .map_err(format_redis_error)
+    }
+}
+
+/// A message received on a subscribed channel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Message {
+    /// The channel the message was published on (the matched channel, for `psubscribe`).
+    pub channel: String,
+    pub payload: Vec<u8>,
+}
+
+impl TryFrom<redis::Msg> for Message {
+    type Error = Error;
+
+    fn try_from(msg: redis::Msg) -> Result<Self> {
+        let channel = msg.get_channel_name().to_string();
+        let payload = msg.get_payload().map_err(format_redis_error)?;
+        Ok(Self { channel, payload })
+    }
+}
+
+/// A live pub/sub subscription, opened via [`RedisClient::subscribe`] or
+/// [`RedisClient::psubscribe`].
+pub struct Subscription {
+    pubsub: redis::aio::PubSub,
+}
+
+impl Subscription {
+    /// Turn this subscription into a stream of incoming messages.
+    pub fn into_stream(self) -> impl futures::Stream<Item = Result<Message>> {
+        futures::stream::unfold(self.pubsub, |mut pubsub| async move {
+            let msg = pubsub.on_message().next().await?;
+            Some((Message::try_from(msg), pubsub))
+        })
+    }
+}
+
+/// The decoded reply for one command queued on a [`Pipeline`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PipelineValue {
+    /// The reply to a `get`, present only when the key existed.
+    Bytes(Option<Vec<u8>>),
+    /// The reply to a `set`/`delete`/`append`, which carries no useful payload.
+    Unit,
+}
+
+enum PipelineCommand {
+    Get,
+    Unit,
+}
+
+/// A builder that accumulates `get`/`set`/`delete`/`append` commands and runs
+/// them together against a single checked-out connection.
+///
+/// By default the commands are pipelined (sent together, executed in order,
+/// but not wrapped in a transaction). Call [`Pipeline::atomic`] to wrap them
+/// in a Redis `MULTI`/`EXEC` transaction instead.
+pub struct Pipeline<'a> {
+    client: &'a RedisClient,
+    pipe: redis::Pipeline,
+    commands: Vec<PipelineCommand>,
+}
+
+impl<'a> Pipeline<'a> {
+    fn new(client: &'a RedisClient) -> Self {
+        Self {
+            client,
+            pipe: redis::pipe(),
+            commands: Vec::new(),
+        }
+    }
+
+    /// Run the queued commands as an atomic `MULTI`/`EXEC` transaction.
+    pub fn atomic(mut self) -> Self {
+        self.pipe.atomic();
+        self
+    }
+
+    pub fn get(mut self, key: &str) -> Self {
+        self.pipe.get(key);
+        self.commands.push(PipelineCommand::Get);
+        self
+    }
+
+    pub fn set(mut self, key: &str, value: &[u8]) -> Self {
+        self.pipe.set(key, value);
+        self.commands.push(PipelineCommand::Unit);
+        self
+    }
+
+    pub fn delete(mut self, key: &str) -> Self {
+        self.pipe.del(key);
+        self.commands.push(PipelineCommand::Unit);
+        self
+    }
+
+    pub fn append(mut self, key: &str, value: &[u8]) -> Self {
+        self.pipe.append(key, value);
+        self.commands.push(PipelineCommand::Unit);
+        self
+    }
+
+    /// Execute the accumulated commands in a single round-trip, returning one
+    /// [`PipelineValue`] per queued command, in order.
+    pub async fn query(self) -> Result<Vec<PipelineValue>> {
+        let mut conn = self.client.pool.get().await.map_err(format_pool_error)?;
+        let values: Vec<redis::Value> = match &mut conn.kind {
+            RedisConnectionKind::Single(conn) => {
+                self.pipe.query_async(conn).await.map_err(format_redis_error)?
             }
-            RedisConnection::Cluster(mut conn) => {
-                conn.append(key, value).await.map_err(format_redis_error)?;
+            RedisConnectionKind::Cluster(conn) => {
+                self.pipe.query_async(conn).await.map_err(format_redis_error)?
             }
-        }
-        Ok(())
+        };
+
+        self.commands
+            .into_iter()
+            .zip(values)
+            .map(|(command, value)| match command {
+                PipelineCommand::Get => redis::from_redis_value(&value)
+                    .map(PipelineValue::Bytes)
+                    .map_err(format_redis_error),
+                PipelineCommand::Unit => Ok(PipelineValue::Unit),
+            })
+            .collect()
     }
 }
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::path::PathBuf;
+use std::time::Duration;
 
 #[derive(Clone, Serialize, Deserialize)]
 pub struct RedisSettings {
@@ -28,6 +30,58 @@ pub struct RedisSettings {
     ///
     /// default is db 0
     pub db: i64,
+    /// the maximum number of connections the pool will keep open at once.
+    ///
+    /// default is 10
+    pub pool_max_size: Option<u32>,
+    /// the minimum number of idle connections the pool will try to maintain.
+    ///
+    /// default is None, meaning the pool does not pre-warm idle connections
+    pub min_idle: Option<u32>,
+    /// how long to wait for a pooled connection before giving up.
+    ///
+    /// default is None, meaning bb8's default timeout (30 seconds) applies
+    pub connection_timeout: Option<Duration>,
+    /// the number of times a command is retried after a transient failure
+    /// (a dropped connection, the cluster being down, or, for `get`, `mget`
+    /// and `delete`, a timeout). Cluster `MOVED`/`ASK` redirections are
+    /// followed by the cluster connection itself and are not retried here;
+    /// `Pipeline` queries are not retried.
+    ///
+    /// default is 0, meaning no automatic retries
+    pub max_retries: Option<u32>,
+    /// the base delay used for the retry backoff; each subsequent retry
+    /// doubles this delay and adds jitter.
+    ///
+    /// default is 50ms
+    pub retry_base_delay: Option<Duration>,
+    /// for cluster mode, whether `get_with_preference`/`mget_with_preference`
+    /// with a non-`Primary` `ReadPreference` may be served from replicas;
+    /// plain `get`/`mget` still read from the primaries. Ignored outside
+    /// cluster mode.
+    ///
+    /// default is false
+    pub read_from_replicas: bool,
+    /// a PEM-encoded CA certificate to trust when connecting via `rediss://`,
+    /// in addition to (or instead of) the system's trust store.
+    ///
+    /// default is None, meaning only publicly-trusted CAs are accepted
+    pub tls_ca_cert: Option<PathBuf>,
+    /// a PEM-encoded client certificate to present for mutual TLS. Must be
+    /// set together with `tls_client_key`.
+    ///
+    /// default is None
+    pub tls_client_cert: Option<PathBuf>,
+    /// the PEM-encoded private key for `tls_client_cert`. Must be set
+    /// together with `tls_client_cert`.
+    ///
+    /// default is None
+    pub tls_client_key: Option<PathBuf>,
+    /// skip verifying the server's TLS certificate. Only ever useful for
+    /// local testing against a self-signed server.
+    ///
+    /// default is false
+    pub tls_insecure: bool,
 }
 
 impl Debug for RedisSettings {
@@ -47,6 +101,32 @@ impl Debug for RedisSettings {
         if self.password.is_some() {
             d.field("password", &"<redacted>");
         }
+        if let Some(pool_max_size) = self.pool_max_size {
+            d.field("pool_max_size", &pool_max_size);
+        }
+        if let Some(min_idle) = self.min_idle {
+            d.field("min_idle", &min_idle);
+        }
+        if let Some(connection_timeout) = self.connection_timeout {
+            d.field("connection_timeout", &connection_timeout);
+        }
+        if let Some(max_retries) = self.max_retries {
+            d.field("max_retries", &max_retries);
+        }
+        if let Some(retry_base_delay) = self.retry_base_delay {
+            d.field("retry_base_delay", &retry_base_delay);
+        }
+        d.field("read_from_replicas", &self.read_from_replicas);
+        if let Some(tls_ca_cert) = &self.tls_ca_cert {
+            d.field("tls_ca_cert", tls_ca_cert);
+        }
+        if let Some(tls_client_cert) = &self.tls_client_cert {
+            d.field("tls_client_cert", tls_client_cert);
+        }
+        if self.tls_client_key.is_some() {
+            d.field("tls_client_key", &"<redacted>");
+        }
+        d.field("tls_insecure", &self.tls_insecure);
 
         d.finish_non_exhaustive()
     }